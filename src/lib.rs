@@ -63,6 +63,84 @@ impl std::error::Error for GreetingError {}
 /// Result type for greeting operations
 pub type GreetingResult<T> = Result<T, GreetingError>;
 
+/// A parsed BCP-47 language identifier, e.g. `zh-Hant-TW`.
+///
+/// Modeled loosely on `unic-langid`'s `LanguageIdentifier`: a primary
+/// language subtag plus optional script and region subtags. Only the
+/// subset of the grammar needed to distinguish dialects is recognized;
+/// variant and extension subtags are not supported, and an identifier
+/// that carries one is rejected by [`LanguageId::parse`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LanguageId {
+    /// Primary language subtag, lowercase (e.g. `"en"`, `"zh"`).
+    pub language: String,
+    /// Script subtag, titlecase (e.g. `"Hant"`), if present.
+    pub script: Option<String>,
+    /// Region subtag, uppercase letters or digits (e.g. `"US"`, `"419"`), if present.
+    pub region: Option<String>,
+}
+
+impl LanguageId {
+    /// Parse a BCP-47 identifier such as `"zh-Hant-TW"` or `"en_US"`.
+    ///
+    /// Subtags are separated by `-` or `_`. The first subtag must be a
+    /// 2-3 letter primary language code. An optional 4-letter script
+    /// subtag and an optional region subtag (2 letters or 3 digits) may
+    /// follow, in that order.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GreetingError::InvalidLanguage` if the primary subtag is
+    /// missing or malformed, or if a later subtag is neither a valid
+    /// script nor a valid region.
+    pub fn parse(code: &str) -> GreetingResult<Self> {
+        let mut subtags = code.split(['-', '_']);
+
+        let language = subtags.next().unwrap_or("");
+        if !(2..=3).contains(&language.len()) || !language.bytes().all(|b| b.is_ascii_alphabetic())
+        {
+            return Err(GreetingError::InvalidLanguage(code.to_string()));
+        }
+        let language = language.to_lowercase();
+
+        let mut script = None;
+        let mut region = None;
+
+        for subtag in subtags {
+            if script.is_none() && region.is_none() && is_script_subtag(subtag) {
+                script = Some(titlecase(subtag));
+            } else if region.is_none() && is_region_subtag(subtag) {
+                region = Some(subtag.to_uppercase());
+            } else {
+                return Err(GreetingError::InvalidLanguage(code.to_string()));
+            }
+        }
+
+        Ok(Self {
+            language,
+            script,
+            region,
+        })
+    }
+}
+
+fn is_script_subtag(subtag: &str) -> bool {
+    subtag.len() == 4 && subtag.bytes().all(|b| b.is_ascii_alphabetic())
+}
+
+fn is_region_subtag(subtag: &str) -> bool {
+    (subtag.len() == 2 && subtag.bytes().all(|b| b.is_ascii_alphabetic()))
+        || (subtag.len() == 3 && subtag.bytes().all(|b| b.is_ascii_digit()))
+}
+
+fn titlecase(subtag: &str) -> String {
+    let mut chars = subtag.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().collect::<String>() + &chars.as_str().to_lowercase(),
+        None => String::new(),
+    }
+}
+
 /// Supported languages for multilingual greetings
 #[derive(Debug, Clone, PartialEq)]
 pub enum Language {
@@ -88,13 +166,49 @@ impl Language {
     /// # Returns
     ///
     /// `Language` enum variant, defaults to English for unknown codes
+    ///
+    /// This is a lenient wrapper around `try_from_code` kept for backward
+    /// compatibility; malformed or unrecognized codes silently fall back
+    /// to [`Language::English`] instead of erroring. It also still
+    /// accepts the bare English language names (`"chinese"`, `"spanish"`,
+    /// `"french"`, `"german"`) that predate BCP-47 parsing; `try_from_code`
+    /// does not, since they are not valid language subtags.
     fn from_code(code: &str) -> Self {
         match code.to_lowercase().as_str() {
-            "zh" | "chinese" => Self::Chinese,
-            "es" | "spanish" => Self::Spanish,
-            "fr" | "french" => Self::French,
-            "de" | "german" => Self::German,
-            _ => Self::English,
+            "chinese" => Self::Chinese,
+            "spanish" => Self::Spanish,
+            "french" => Self::French,
+            "german" => Self::German,
+            _ => Self::try_from_code(code).unwrap_or(Self::English),
+        }
+    }
+
+    /// Parse a full BCP-47 language identifier and resolve it to a
+    /// `Language` variant.
+    ///
+    /// Unlike `from_code`, this rejects malformed identifiers instead of
+    /// defaulting to English, and it does not accept the bare English
+    /// language names (e.g. `"chinese"`) that `from_code` keeps for
+    /// backward compatibility. Only the primary language subtag is used
+    /// to pick a variant today; the parsed script and region (see
+    /// [`LanguageId`]) are discarded, but parsing them up front means
+    /// dialect-specific greetings can be layered in later without
+    /// changing the public signature.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GreetingError::InvalidLanguage` if `code` is not a
+    /// well-formed language identifier or its primary subtag does not
+    /// match a supported language.
+    pub fn try_from_code(code: &str) -> GreetingResult<Self> {
+        let id = LanguageId::parse(code)?;
+        match id.language.as_str() {
+            "en" | "eng" => Ok(Self::English),
+            "zh" | "chi" | "zho" => Ok(Self::Chinese),
+            "es" | "spa" => Ok(Self::Spanish),
+            "fr" | "fra" | "fre" => Ok(Self::French),
+            "de" | "ger" | "deu" => Ok(Self::German),
+            _ => Err(GreetingError::InvalidLanguage(code.to_string())),
         }
     }
 
@@ -339,6 +453,66 @@ mod tests {
         assert_eq!(Language::from_code("unknown"), Language::English);
     }
 
+    #[test]
+    fn test_language_from_code_bare_names() {
+        assert_eq!(Language::from_code("chinese"), Language::Chinese);
+        assert_eq!(Language::from_code("spanish"), Language::Spanish);
+        assert_eq!(Language::from_code("french"), Language::French);
+        assert_eq!(Language::from_code("german"), Language::German);
+    }
+
+    #[test]
+    fn test_language_from_code_full_identifier() {
+        assert_eq!(Language::from_code("en-US"), Language::English);
+        assert_eq!(Language::from_code("zh-Hant"), Language::Chinese);
+        assert_eq!(Language::from_code("zh-Hant-TW"), Language::Chinese);
+        assert_eq!(Language::from_code("not a code"), Language::English);
+    }
+
+    #[test]
+    fn test_language_try_from_code() {
+        assert_eq!(Language::try_from_code("en").unwrap(), Language::English);
+        assert_eq!(Language::try_from_code("zh-Hant").unwrap(), Language::Chinese);
+
+        assert!(matches!(
+            Language::try_from_code("not a code"),
+            Err(GreetingError::InvalidLanguage(_))
+        ));
+        assert!(matches!(
+            Language::try_from_code("xx"),
+            Err(GreetingError::InvalidLanguage(_))
+        ));
+    }
+
+    #[test]
+    fn test_language_id_parse() {
+        let id = LanguageId::parse("zh-Hant-TW").unwrap();
+        assert_eq!(id.language, "zh");
+        assert_eq!(id.script.as_deref(), Some("Hant"));
+        assert_eq!(id.region.as_deref(), Some("TW"));
+
+        let id = LanguageId::parse("en_US").unwrap();
+        assert_eq!(id.language, "en");
+        assert_eq!(id.script, None);
+        assert_eq!(id.region.as_deref(), Some("US"));
+
+        let id = LanguageId::parse("es-419").unwrap();
+        assert_eq!(id.language, "es");
+        assert_eq!(id.region.as_deref(), Some("419"));
+
+        let id = LanguageId::parse("DE").unwrap();
+        assert_eq!(id.language, "de");
+    }
+
+    #[test]
+    fn test_language_id_parse_invalid() {
+        assert!(LanguageId::parse("").is_err());
+        assert!(LanguageId::parse("english").is_err());
+        assert!(LanguageId::parse("e").is_err());
+        assert!(LanguageId::parse("en-1234").is_err());
+        assert!(LanguageId::parse("en-Latn-XX-extra").is_err());
+    }
+
     #[test]
     fn test_get_supported_languages() {
         let languages = get_supported_languages();